@@ -1,3 +1,20 @@
+use alloc::{vec, vec::Vec};
+
+/// How to shrink corner radii that would otherwise make adjacent corners
+/// overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationStrategy {
+    /// Figma's rounding-and-smoothing budget: each corner negotiates with
+    /// its two neighbors for a share of the side, via
+    /// [`distribute_and_normalize`].
+    FigmaBudget,
+    /// The CSS Backgrounds and Borders algorithm: find the smallest ratio
+    /// `f = side_length / (radius_start + radius_end)` across all four
+    /// sides, and if `f < 1`, scale every corner radius by it. See
+    /// [`css_spec_normalize`].
+    CssSpec,
+}
+
 pub struct RoundedRectangle {
     pub top_left_corner_radius: f64,
     pub top_right_corner_radius: f64,
@@ -221,3 +238,104 @@ pub fn distribute_and_normalize(rectangle: RoundedRectangle) -> NormalizedCorner
         },
     }
 }
+
+/// The CSS Backgrounds and Borders normalization algorithm: for each side,
+/// compute `side_length / (radius_start + radius_end)` (treated as infinite
+/// when both radii are zero), take the minimum across all four sides, and if
+/// that minimum is below 1, scale every corner radius by it. Unlike
+/// [`distribute_and_normalize`], this scales all corners by a single shared
+/// factor instead of negotiating a budget per corner, matching how browsers
+/// and GTK resolve overlapping `border-radius` values.
+pub fn css_spec_normalize(rectangle: RoundedRectangle) -> NormalizedCorners {
+    let RoundedRectangle {
+        top_left_corner_radius,
+        top_right_corner_radius,
+        bottom_right_corner_radius,
+        bottom_left_corner_radius,
+        width,
+        height,
+    } = rectangle;
+
+    let side_ratio = |side_length: f64, radius_start: f64, radius_end: f64| -> f64 {
+        let sum = radius_start + radius_end;
+        if sum <= 0.0 {
+            f64::INFINITY
+        } else {
+            side_length / sum
+        }
+    };
+
+    let f = side_ratio(width, top_left_corner_radius, top_right_corner_radius)
+        .min(side_ratio(height, top_right_corner_radius, bottom_right_corner_radius))
+        .min(side_ratio(width, bottom_left_corner_radius, bottom_right_corner_radius))
+        .min(side_ratio(height, top_left_corner_radius, bottom_left_corner_radius))
+        .min(1.0);
+
+    let top_left = top_left_corner_radius * f;
+    let top_right = top_right_corner_radius * f;
+    let bottom_left = bottom_left_corner_radius * f;
+    let bottom_right = bottom_right_corner_radius * f;
+
+    // Having scaled every radius so no two adjacent corners overlap, the
+    // remaining per-corner budget for smoothing is just whatever's left on
+    // each of its two sides after its neighbor's share.
+    let budget = |side_width: f64, adjacent_width: f64, side_height: f64, adjacent_height: f64| -> f64 {
+        f64::min(side_width - adjacent_width, side_height - adjacent_height)
+    };
+
+    NormalizedCorners {
+        top_left: NormalizedCorner {
+            radius: top_left,
+            rounding_and_smoothing_budget: budget(width, top_right, height, bottom_left),
+        },
+        top_right: NormalizedCorner {
+            radius: top_right,
+            rounding_and_smoothing_budget: budget(width, top_left, height, bottom_right),
+        },
+        bottom_left: NormalizedCorner {
+            radius: bottom_left,
+            rounding_and_smoothing_budget: budget(width, bottom_right, height, top_left),
+        },
+        bottom_right: NormalizedCorner {
+            radius: bottom_right,
+            rounding_and_smoothing_budget: budget(width, bottom_left, height, top_right),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_spec_normalize_scales_down_overlapping_radii() {
+        // Top/bottom sides are the binding constraint: 10 / (6 + 6) = 5/6,
+        // so every radius should shrink from 6 to 5.
+        let result = css_spec_normalize(RoundedRectangle {
+            top_left_corner_radius: 6.0,
+            top_right_corner_radius: 6.0,
+            bottom_right_corner_radius: 6.0,
+            bottom_left_corner_radius: 6.0,
+            width: 10.0,
+            height: 100.0,
+        });
+        assert!((result.top_left.radius - 5.0).abs() < 1e-9);
+        assert!((result.top_right.radius - 5.0).abs() < 1e-9);
+        assert!((result.bottom_left.radius - 5.0).abs() < 1e-9);
+        assert!((result.bottom_right.radius - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn css_spec_normalize_leaves_non_overlapping_radii_untouched() {
+        let result = css_spec_normalize(RoundedRectangle {
+            top_left_corner_radius: 5.0,
+            top_right_corner_radius: 5.0,
+            bottom_right_corner_radius: 5.0,
+            bottom_left_corner_radius: 5.0,
+            width: 100.0,
+            height: 100.0,
+        });
+        assert_eq!(result.top_left.radius, 5.0);
+        assert_eq!(result.bottom_right.radius, 5.0);
+    }
+}
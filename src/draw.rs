@@ -1,5 +1,12 @@
 #![allow(dead_code, unreachable_code, unused)]
 
+use alloc::{format, string::String, vec, vec::Vec};
+
+use crate::{
+    ops,
+    path_ops::{self, PathOp},
+};
+
 pub struct CornerPathParams {
     a: f64,
     b: f64,
@@ -19,7 +26,7 @@ pub struct CornerParams {
 
 #[inline]
 fn to_radians(degrees: f64) -> f64 {
-    (degrees * std::f64::consts::PI) / 180.0
+    (degrees * core::f64::consts::PI) / 180.0
 }
 
 // The article from figma's blog
@@ -60,17 +67,17 @@ pub fn get_path_params_for_corner(corner_params: CornerParams) -> CornerPathPara
 
     let arc_measure = 90.0 * (1.0 - corner_smoothing);
     let arc_section_length =
-        (to_radians(arc_measure / 2.0)).sin() * corner_radius * (2.0_f64).sqrt();
+        ops::sin(to_radians(arc_measure / 2.0)) * corner_radius * ops::sqrt(2.0_f64);
 
     // In the article this is the distance between 2 control points: P3 and P4
     let angle_alpha = (90.0 - arc_measure) / 2.0;
-    let p3_to_p4_distance = corner_radius * (to_radians(angle_alpha / 2.0)).tan();
+    let p3_to_p4_distance = corner_radius * ops::tan(to_radians(angle_alpha / 2.0));
 
     // a, b, c and d are from figure 11.1 in the article
     let angle_beta = 45.0 * corner_smoothing;
     let angle_beta_rad = to_radians(angle_beta);
-    let c = p3_to_p4_distance * (angle_beta_rad).cos();
-    let d = c * (angle_beta_rad).tan();
+    let c = p3_to_p4_distance * ops::cos(angle_beta_rad);
+    let d = c * ops::tan(angle_beta_rad);
 
     let mut b = (p - arc_section_length - c - d) / 3.0;
     let mut a = 2.0 * b;
@@ -109,15 +116,7 @@ pub struct SVGPathInput<'a> {
 }
 
 fn draw_top_right_path(path_params: &CornerPathParams) -> String {
-    let CornerPathParams {
-        corner_radius,
-        a,
-        b,
-        c,
-        d,
-        p,
-        arc_section_length,
-    } = path_params;
+    let CornerPathParams { corner_radius, a, b, c, d, p, arc_section_length } = path_params;
 
     if *corner_radius > 0.0 {
         format!(
@@ -147,15 +146,7 @@ fn draw_top_right_path(path_params: &CornerPathParams) -> String {
 }
 
 fn draw_bottom_right_path(path_params: &CornerPathParams) -> String {
-    let CornerPathParams {
-        corner_radius,
-        a,
-        b,
-        c,
-        d,
-        p,
-        arc_section_length,
-    } = path_params;
+    let CornerPathParams { corner_radius, a, b, c, d, p, arc_section_length } = path_params;
 
     if *corner_radius > 0.0 {
         format!(
@@ -187,15 +178,7 @@ fn draw_bottom_right_path(path_params: &CornerPathParams) -> String {
 }
 
 fn draw_bottom_left_path(path_params: &CornerPathParams) -> String {
-    let CornerPathParams {
-        corner_radius,
-        a,
-        b,
-        c,
-        d,
-        p,
-        arc_section_length,
-    } = path_params;
+    let CornerPathParams { corner_radius, a, b, c, d, p, arc_section_length } = path_params;
 
     if *corner_radius > 0.0 {
         format!(
@@ -227,15 +210,7 @@ fn draw_bottom_left_path(path_params: &CornerPathParams) -> String {
 }
 
 fn draw_top_left_path(path_params: &CornerPathParams) -> String {
-    let CornerPathParams {
-        corner_radius,
-        a,
-        b,
-        c,
-        d,
-        p,
-        arc_section_length,
-    } = path_params;
+    let CornerPathParams { corner_radius, a, b, c, d, p, arc_section_length } = path_params;
 
     if *corner_radius > 0.0 {
         format!(
@@ -266,6 +241,175 @@ fn draw_top_left_path(path_params: &CornerPathParams) -> String {
     }
 }
 
+fn top_right_ops(cur: &mut (f64, f64), p: &CornerPathParams, cubic_only: bool) -> Vec<PathOp> {
+    if p.corner_radius <= 0.0 {
+        let end = (cur.0 + p.p, cur.1);
+        *cur = end;
+        return vec![PathOp::LineTo(end.0, end.1)];
+    }
+
+    let mut ops = Vec::with_capacity(3);
+
+    let end = (cur.0 + p.a + p.b + p.c, cur.1 + p.d);
+    ops.push(PathOp::CubicTo((cur.0 + p.a, cur.1), (cur.0 + p.a + p.b, cur.1), end));
+    *cur = end;
+
+    let arc_end = (cur.0 + p.arc_section_length, cur.1 + p.arc_section_length);
+    push_arc(&mut ops, *cur, p.corner_radius, true, arc_end, cubic_only);
+    *cur = arc_end;
+
+    let end = (cur.0 + p.d, cur.1 + p.a + p.b + p.c);
+    ops.push(PathOp::CubicTo(
+        (cur.0 + p.d, cur.1 + p.c),
+        (cur.0 + p.d, cur.1 + p.b + p.c),
+        end,
+    ));
+    *cur = end;
+
+    ops
+}
+
+fn bottom_right_ops(cur: &mut (f64, f64), p: &CornerPathParams, cubic_only: bool) -> Vec<PathOp> {
+    if p.corner_radius <= 0.0 {
+        let end = (cur.0, cur.1 + p.p);
+        *cur = end;
+        return vec![PathOp::LineTo(end.0, end.1)];
+    }
+
+    let mut ops = Vec::with_capacity(3);
+
+    let end = (cur.0 - p.d, cur.1 + p.a + p.b + p.c);
+    ops.push(PathOp::CubicTo((cur.0, cur.1 + p.a), (cur.0, cur.1 + p.a + p.b), end));
+    *cur = end;
+
+    let arc_end = (cur.0 - p.arc_section_length, cur.1 + p.arc_section_length);
+    push_arc(&mut ops, *cur, p.corner_radius, true, arc_end, cubic_only);
+    *cur = arc_end;
+
+    let end = (cur.0 - (p.a + p.b + p.c), cur.1 + p.d);
+    ops.push(PathOp::CubicTo(
+        (cur.0 - p.c, cur.1 + p.d),
+        (cur.0 - (p.b + p.c), cur.1 + p.d),
+        end,
+    ));
+    *cur = end;
+
+    ops
+}
+
+fn bottom_left_ops(cur: &mut (f64, f64), p: &CornerPathParams, cubic_only: bool) -> Vec<PathOp> {
+    if p.corner_radius <= 0.0 {
+        let end = (cur.0 - p.p, cur.1);
+        *cur = end;
+        return vec![PathOp::LineTo(end.0, end.1)];
+    }
+
+    let mut ops = Vec::with_capacity(3);
+
+    let end = (cur.0 - (p.a + p.b + p.c), cur.1 - p.d);
+    ops.push(PathOp::CubicTo((cur.0 - p.a, cur.1), (cur.0 - (p.a + p.b), cur.1), end));
+    *cur = end;
+
+    let arc_end = (cur.0 - p.arc_section_length, cur.1 - p.arc_section_length);
+    push_arc(&mut ops, *cur, p.corner_radius, true, arc_end, cubic_only);
+    *cur = arc_end;
+
+    let end = (cur.0 - p.d, cur.1 - (p.a + p.b + p.c));
+    ops.push(PathOp::CubicTo(
+        (cur.0 - p.d, cur.1 - p.c),
+        (cur.0 - p.d, cur.1 - (p.b + p.c)),
+        end,
+    ));
+    *cur = end;
+
+    ops
+}
+
+fn top_left_ops(cur: &mut (f64, f64), p: &CornerPathParams, cubic_only: bool) -> Vec<PathOp> {
+    if p.corner_radius <= 0.0 {
+        let end = (cur.0, cur.1 - p.p);
+        *cur = end;
+        return vec![PathOp::LineTo(end.0, end.1)];
+    }
+
+    let mut ops = Vec::with_capacity(3);
+
+    let end = (cur.0 + p.d, cur.1 - (p.a + p.b + p.c));
+    ops.push(PathOp::CubicTo((cur.0, cur.1 - p.a), (cur.0, cur.1 - (p.a + p.b)), end));
+    *cur = end;
+
+    let arc_end = (cur.0 + p.arc_section_length, cur.1 - p.arc_section_length);
+    push_arc(&mut ops, *cur, p.corner_radius, true, arc_end, cubic_only);
+    *cur = arc_end;
+
+    let end = (cur.0 + p.a + p.b + p.c, cur.1 - p.d);
+    ops.push(PathOp::CubicTo(
+        (cur.0 + p.c, cur.1 - p.d),
+        (cur.0 + p.b + p.c, cur.1 - p.d),
+        end,
+    ));
+    *cur = end;
+
+    ops
+}
+
+fn push_arc(
+    ops: &mut Vec<PathOp>,
+    start: (f64, f64),
+    radius: f64,
+    sweep: bool,
+    end: (f64, f64),
+    cubic_only: bool,
+) {
+    if cubic_only {
+        ops.extend(path_ops::arc_to_cubics(start, radius, radius, sweep, end));
+    } else {
+        ops.push(PathOp::Arc(radius, radius, sweep, end));
+    }
+}
+
+/// Builds the same geometry as [`get_svg_path_from_path_params`] as a
+/// sequence of absolute-coordinate [`PathOp`]s instead of an SVG string. When
+/// `cubic_only` is set, corner arcs are converted to cubic Beziers so the
+/// output contains only `MoveTo`/`LineTo`/`CubicTo`/`Close`.
+pub fn get_path_ops_from_path_params(input: &SVGPathInput, cubic_only: bool) -> Vec<PathOp> {
+    let SVGPathInput {
+        width,
+        height,
+        top_right_path_params,
+        bottom_right_path_params,
+        bottom_left_path_params,
+        top_left_path_params,
+    } = input;
+    let (width, height) = (*width, *height);
+
+    let mut ops = Vec::new();
+    let mut cur = (width - top_right_path_params.p, 0.0);
+    ops.push(PathOp::MoveTo(cur.0, cur.1));
+    ops.extend(top_right_ops(&mut cur, top_right_path_params, cubic_only));
+
+    cur = (width, height - bottom_right_path_params.p);
+    ops.push(PathOp::LineTo(cur.0, cur.1));
+    ops.extend(bottom_right_ops(&mut cur, bottom_right_path_params, cubic_only));
+
+    cur = (bottom_left_path_params.p, height);
+    ops.push(PathOp::LineTo(cur.0, cur.1));
+    ops.extend(bottom_left_ops(&mut cur, bottom_left_path_params, cubic_only));
+
+    cur = (0.0, top_left_path_params.p);
+    ops.push(PathOp::LineTo(cur.0, cur.1));
+    ops.extend(top_left_ops(&mut cur, top_left_path_params, cubic_only));
+
+    ops.push(PathOp::Close);
+    ops
+}
+
+/// Renders the outline directly as an SVG `d` attribute with relative
+/// (`c`/`a`/`l`) corner commands, matching the format this crate has always
+/// produced. This is kept as its own code path, separate from
+/// [`get_path_ops_from_path_params`]'s absolute-coordinate [`PathOp`]s,
+/// so existing consumers doing string equality, snapshot tests, or caching
+/// on [`crate::get_svg_path`]'s return value don't see it change shape.
 pub fn get_svg_path_from_path_params(input: &SVGPathInput) -> String {
     let SVGPathInput {
         width,
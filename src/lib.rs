@@ -1,7 +1,20 @@
+#![no_std]
 #![allow(unused_macros, unused_variables)]
 
+extern crate alloc;
+
+#[cfg(not(feature = "libm"))]
+extern crate std;
+
+use alloc::{string::String, vec::Vec};
+
 mod distribute;
 mod draw;
+mod ops;
+mod path_ops;
+mod rasterize;
+mod shape;
+mod stroke;
 
 use crate::{
     distribute::{NormalizedCorners, RoundedRectangle},
@@ -9,6 +22,12 @@ use crate::{
 };
 use draw::CornerParams;
 
+pub use distribute::NormalizationStrategy;
+pub use path_ops::PathOp;
+pub use rasterize::rasterize;
+pub use shape::{BoundingBox, Squircle};
+pub use stroke::{LineCap, LineJoin, StrokeStyle};
+
 pub struct SquircleParams {
     pub width: f64,
     pub height: f64,
@@ -19,9 +38,34 @@ pub struct SquircleParams {
     pub bottom_right_corner_radius: Option<f64>,
     pub bottom_left_corner_radius: Option<f64>,
     pub preserve_smoothing: Option<bool>,
+    /// How overlapping corner radii get shrunk when the rectangle is too
+    /// small to fit them as given. Defaults to `FigmaBudget`.
+    pub normalization_strategy: Option<NormalizationStrategy>,
 }
 
-pub fn get_svg_path(params: &SquircleParams) -> String {
+struct CornerPathParamsSet {
+    width: f64,
+    height: f64,
+    top_left: draw::CornerPathParams,
+    top_right: draw::CornerPathParams,
+    bottom_left: draw::CornerPathParams,
+    bottom_right: draw::CornerPathParams,
+}
+
+impl CornerPathParamsSet {
+    fn as_input(&self) -> SVGPathInput<'_> {
+        SVGPathInput {
+            width: self.width,
+            height: self.height,
+            top_left_path_params: &self.top_left,
+            top_right_path_params: &self.top_right,
+            bottom_left_path_params: &self.bottom_left,
+            bottom_right_path_params: &self.bottom_right,
+        }
+    }
+}
+
+fn compute_corner_path_params(params: &SquircleParams) -> CornerPathParamsSet {
     let SquircleParams {
         width,
         height,
@@ -32,6 +76,7 @@ pub fn get_svg_path(params: &SquircleParams) -> String {
         bottom_right_corner_radius,
         bottom_left_corner_radius,
         preserve_smoothing,
+        normalization_strategy,
     } = params;
     let width = *width;
     let height = *height;
@@ -43,6 +88,7 @@ pub fn get_svg_path(params: &SquircleParams) -> String {
     let bottom_left_corner_radius = bottom_left_corner_radius.unwrap_or(corner_radius);
     let bottom_right_corner_radius = bottom_right_corner_radius.unwrap_or(corner_radius);
     let preserve_smoothing = preserve_smoothing.unwrap_or(false);
+    let normalization_strategy = normalization_strategy.unwrap_or(NormalizationStrategy::FigmaBudget);
 
     if top_left_corner_radius == top_right_corner_radius
         && top_right_corner_radius == bottom_right_corner_radius
@@ -52,65 +98,133 @@ pub fn get_svg_path(params: &SquircleParams) -> String {
         let rounding_and_smoothing_budget = f64::min(width, height) / 2.0;
         let corner_radius = f64::min(top_left_corner_radius, rounding_and_smoothing_budget);
 
-        let path_params = draw::get_path_params_for_corner(CornerParams {
-            corner_radius,
-            corner_smoothing,
-            preserve_smoothing,
-            rounding_and_smoothing_budget,
-        });
+        let make_params = || {
+            draw::get_path_params_for_corner(CornerParams {
+                corner_radius,
+                corner_smoothing,
+                preserve_smoothing,
+                rounding_and_smoothing_budget,
+            })
+        };
 
-        return draw::get_svg_path_from_path_params(&SVGPathInput {
+        return CornerPathParamsSet {
             width,
             height,
-            top_left_path_params: &path_params,
-            top_right_path_params: &path_params,
-            bottom_left_path_params: &path_params,
-            bottom_right_path_params: &path_params,
-        });
+            top_left: make_params(),
+            top_right: make_params(),
+            bottom_left: make_params(),
+            bottom_right: make_params(),
+        };
     }
 
-    let NormalizedCorners {
-        top_left,
-        top_right,
-        bottom_left,
-        bottom_right,
-    } = distribute::distribute_and_normalize(RoundedRectangle {
+    let rectangle = RoundedRectangle {
         top_left_corner_radius,
         top_right_corner_radius,
         bottom_right_corner_radius,
         bottom_left_corner_radius,
         width,
         height,
-    });
+    };
+    let NormalizedCorners {
+        top_left,
+        top_right,
+        bottom_left,
+        bottom_right,
+    } = match normalization_strategy {
+        NormalizationStrategy::FigmaBudget => distribute::distribute_and_normalize(rectangle),
+        NormalizationStrategy::CssSpec => distribute::css_spec_normalize(rectangle),
+    };
 
-    let result = draw::get_svg_path_from_path_params(&SVGPathInput {
+    CornerPathParamsSet {
         width,
         height,
-        top_left_path_params: &draw::get_path_params_for_corner(CornerParams {
+        top_left: draw::get_path_params_for_corner(CornerParams {
             corner_radius: top_left.radius,
             corner_smoothing,
             preserve_smoothing,
             rounding_and_smoothing_budget: top_left.rounding_and_smoothing_budget,
         }),
-        top_right_path_params: &draw::get_path_params_for_corner(CornerParams {
+        top_right: draw::get_path_params_for_corner(CornerParams {
             corner_radius: top_right.radius,
             corner_smoothing,
             preserve_smoothing,
             rounding_and_smoothing_budget: top_right.rounding_and_smoothing_budget,
         }),
-        bottom_right_path_params: &draw::get_path_params_for_corner(CornerParams {
+        bottom_right: draw::get_path_params_for_corner(CornerParams {
             corner_radius: bottom_right.radius,
             corner_smoothing,
             preserve_smoothing,
             rounding_and_smoothing_budget: bottom_right.rounding_and_smoothing_budget,
         }),
-        bottom_left_path_params: &draw::get_path_params_for_corner(CornerParams {
+        bottom_left: draw::get_path_params_for_corner(CornerParams {
             corner_radius: bottom_left.radius,
             corner_smoothing,
             preserve_smoothing,
             rounding_and_smoothing_budget: bottom_left.rounding_and_smoothing_budget,
         }),
-    });
+    }
+}
+
+pub fn get_svg_path(params: &SquircleParams) -> String {
+    let set = compute_corner_path_params(params);
+    draw::get_svg_path_from_path_params(&set.as_input())
+}
+
+/// Builds the squircle outline as a sequence of absolute-coordinate
+/// [`PathOp`]s instead of an SVG string, for consumers that want to feed the
+/// geometry straight into a renderer without re-parsing a string.
+pub fn get_path_ops(params: &SquircleParams) -> Vec<PathOp> {
+    let set = compute_corner_path_params(params);
+    draw::get_path_ops_from_path_params(&set.as_input(), false)
+}
+
+/// Like [`get_path_ops`], but corner arcs are converted to cubic Beziers so
+/// the result only ever contains `MoveTo`/`LineTo`/`CubicTo`/`Close`.
+pub fn get_cubic_path_ops(params: &SquircleParams) -> Vec<PathOp> {
+    let set = compute_corner_path_params(params);
+    draw::get_path_ops_from_path_params(&set.as_input(), true)
+}
+
+/// Builds a stroked outline of the squircle as [`PathOp`]s: the border
+/// contour is flattened to a polyline within `tolerance` and offset inward
+/// and outward by half of `style.width`, producing a ring suitable for
+/// even-odd or nonzero filling.
+pub fn get_stroke_path_ops(params: &SquircleParams, style: &StrokeStyle, tolerance: f64) -> Vec<PathOp> {
+    stroke::stroke_ops(&get_path_ops(params), style, tolerance)
+}
 
-    result
+/// Like [`get_stroke_path_ops`], rendered as an SVG `d` attribute.
+pub fn get_stroke_svg_path(params: &SquircleParams, style: &StrokeStyle, tolerance: f64) -> String {
+    stroke::stroke_svg_string(&get_path_ops(params), style, tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pins [`get_svg_path`]'s relative-command output format so it can't
+    /// silently drift (e.g. to the absolute commands [`PathOp`] uses)
+    /// without the change being visible in review.
+    #[test]
+    fn get_svg_path_emits_relative_corner_commands() {
+        let params = SquircleParams {
+            width: 100.0,
+            height: 100.0,
+            corner_smoothing: 0.6,
+            corner_radius: Some(20.0),
+            top_left_corner_radius: None,
+            top_right_corner_radius: None,
+            bottom_right_corner_radius: None,
+            bottom_left_corner_radius: None,
+            preserve_smoothing: Some(false),
+            normalization_strategy: None,
+        };
+        let expected = "M 68.0000 0 c 11.2011 0 16.8016 0 21.0798 2.1799 a 20.0000 20.0000 0 0 1 8.7403 8.7403 \
+c 2.1799 4.2782 2.1799 9.8788 2.1799 21.0798 L 100.0000 68.0000 c 0 11.2011 0 16.8016 -2.1799 21.0798 \
+a 20.0000 20.0000 0 0 1 -8.7403 8.7403 c -4.2782 2.1799 -9.8788 2.1799 -21.0798 2.1799 L 32.0000 100.0000 \
+c -11.2011 0 -16.8016 0 -21.0798 -2.1799 a 20.0000 20.0000 0 0 1 -8.7403 -8.7403 \
+c -2.1799 -4.2782 -2.1799 -9.8788 -2.1799 -21.0798 L 0 32.0000 c 0 -11.2011 0 -16.8016 2.1799 -21.0798 \
+a 20.0000 20.0000 0 0 1 8.7403 -8.7403 c 4.2782 -2.1799 9.8788 -2.1799 21.0798 -2.1799 Z";
+        assert_eq!(get_svg_path(&params), expected);
+    }
 }
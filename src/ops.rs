@@ -0,0 +1,109 @@
+//! Float operations routed through either `std` or [`libm`], selected by the
+//! `libm` cargo feature, so every transcendental call in [`crate::distribute`]
+//! and [`crate::draw`] (and the geometry helpers built on top of them) gives
+//! bit-identical results across platforms and Rust versions, and so the
+//! crate can run where `std` isn't available.
+
+#[cfg(feature = "libm")]
+mod backend {
+    pub fn sin(x: f64) -> f64 {
+        libm::sin(x)
+    }
+
+    pub fn cos(x: f64) -> f64 {
+        libm::cos(x)
+    }
+
+    pub fn tan(x: f64) -> f64 {
+        libm::tan(x)
+    }
+
+    pub fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+
+    pub fn atan2(y: f64, x: f64) -> f64 {
+        libm::atan2(y, x)
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        libm::pow(x, n as f64)
+    }
+
+    pub fn floor(x: f64) -> f64 {
+        libm::floor(x)
+    }
+
+    pub fn ceil(x: f64) -> f64 {
+        libm::ceil(x)
+    }
+}
+
+#[cfg(not(feature = "libm"))]
+mod backend {
+    pub fn sin(x: f64) -> f64 {
+        x.sin()
+    }
+
+    pub fn cos(x: f64) -> f64 {
+        x.cos()
+    }
+
+    pub fn tan(x: f64) -> f64 {
+        x.tan()
+    }
+
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    pub fn atan2(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        x.powi(n)
+    }
+
+    pub fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+
+    pub fn ceil(x: f64) -> f64 {
+        x.ceil()
+    }
+}
+
+pub use backend::{atan2, ceil, cos, floor, powi, sin, sqrt, tan};
+
+/// `(sin(x), cos(x))`, composed from [`sin`] and [`cos`] since `libm` has no
+/// combined entry point with the same signature as `f64::sin_cos`.
+pub fn sin_cos(x: f64) -> (f64, f64) {
+    (sin(x), cos(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn powi_matches_repeated_multiplication() {
+        assert!((powi(2.0, 10) - 1024.0).abs() < 1e-9);
+        assert_eq!(powi(3.0, 0), 1.0);
+    }
+
+    #[test]
+    fn floor_and_ceil_bracket_a_fraction() {
+        assert_eq!(floor(1.5), 1.0);
+        assert_eq!(ceil(1.5), 2.0);
+        assert_eq!(floor(-1.5), -2.0);
+        assert_eq!(ceil(-1.5), -1.0);
+    }
+
+    #[test]
+    fn sin_cos_matches_the_unit_circle_at_zero() {
+        let (s, c) = sin_cos(0.0);
+        assert!(s.abs() < 1e-9);
+        assert!((c - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,286 @@
+//! Structured, absolute-coordinate path representation.
+//!
+//! [`crate::get_path_ops`] produces a sequence of [`PathOp`]s describing the
+//! same geometry as [`crate::get_svg_path`], so consumers that want to feed
+//! the squircle straight into a renderer (wgpu, lyon, tiny-skia, ...) don't
+//! have to re-parse an SVG `d` string. [`to_svg_string`] renders the ops back
+//! into one; [`crate::get_svg_path`] doesn't use it, since it predates this
+//! module and keeps emitting its own relative-command format (see
+//! [`crate::draw::get_svg_path_from_path_params`]).
+
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::ops;
+
+/// A single absolute-coordinate path operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathOp {
+    MoveTo(f64, f64),
+    LineTo(f64, f64),
+    /// Cubic Bezier to the final point, via two control points.
+    CubicTo((f64, f64), (f64, f64), (f64, f64)),
+    /// SVG-style elliptical arc: radii, sweep flag, and the end point.
+    /// Every arc this crate draws has a rotation of 0 and a large-arc-flag
+    /// of 0 (it never spans more than 90 degrees).
+    Arc(f64, f64, bool, (f64, f64)),
+    Close,
+}
+
+/// Renders a sequence of [`PathOp`]s into an SVG `d` attribute using
+/// absolute commands.
+pub fn to_svg_string(ops: &[PathOp]) -> String {
+    let mut out = String::new();
+    for op in ops {
+        match op {
+            PathOp::MoveTo(x, y) => out.push_str(&format!("M {:.4} {:.4} ", x, y)),
+            PathOp::LineTo(x, y) => out.push_str(&format!("L {:.4} {:.4} ", x, y)),
+            PathOp::CubicTo((x1, y1), (x2, y2), (x3, y3)) => out.push_str(&format!(
+                "C {:.4} {:.4} {:.4} {:.4} {:.4} {:.4} ",
+                x1, y1, x2, y2, x3, y3
+            )),
+            PathOp::Arc(rx, ry, sweep, (x, y)) => out.push_str(&format!(
+                "A {:.4} {:.4} 0 0 {} {:.4} {:.4} ",
+                rx,
+                ry,
+                if *sweep { 1 } else { 0 },
+                x,
+                y
+            )),
+            PathOp::Close => out.push_str("Z "),
+        }
+    }
+    out.trim_end().to_string()
+}
+
+/// Converts a single SVG-style arc segment into one or two cubic Beziers,
+/// via the standard arc-to-center parameterization: recover the ellipse
+/// center and start/end angles from the endpoints and radii, then split the
+/// swept angle into sub-arcs of at most 90 degrees, each approximated with
+/// control-point handle length `k = (4/3)*tan(theta/4)*r`.
+pub(crate) fn arc_to_cubics(
+    start: (f64, f64),
+    rx: f64,
+    ry: f64,
+    sweep: bool,
+    end: (f64, f64),
+) -> Vec<PathOp> {
+    if rx <= 0.0 || ry <= 0.0 {
+        return vec![PathOp::LineTo(end.0, end.1)];
+    }
+
+    let (sx, sy) = start;
+    let (ex, ey) = end;
+    let mx = (sx + ex) / 2.0;
+    let my = (sy + ey) / 2.0;
+    let dx = (ex - sx) / 2.0;
+    let dy = (ey - sy) / 2.0;
+
+    // Every arc this crate emits is circular (rx == ry) with a rotation of
+    // zero and a large-arc-flag of 0, so of the two centers satisfying the
+    // endpoints, the sweep flag picks which side of the chord it's on.
+    let chord_len = ops::sqrt(dx * dx + dy * dy).max(f64::EPSILON);
+    let h = ops::sqrt((rx * rx - chord_len * chord_len).max(0.0));
+    let (ux, uy) = (-dy / chord_len, dx / chord_len);
+    let sign = if sweep { 1.0 } else { -1.0 };
+    let center = (mx + sign * h * ux, my + sign * h * uy);
+
+    let start_angle = ops::atan2(sy - center.1, sx - center.0);
+    let mut end_angle = ops::atan2(ey - center.1, ex - center.0);
+    if sweep && end_angle < start_angle {
+        end_angle += 2.0 * PI;
+    } else if !sweep && end_angle > start_angle {
+        end_angle -= 2.0 * PI;
+    }
+
+    arc_between_angles(center, rx, ry, start_angle, end_angle)
+}
+
+fn arc_between_angles(
+    center: (f64, f64),
+    rx: f64,
+    ry: f64,
+    start_angle: f64,
+    end_angle: f64,
+) -> Vec<PathOp> {
+    let total = end_angle - start_angle;
+    let segments = ops::ceil(total.abs() / FRAC_PI_2).max(1.0) as usize;
+    let step = total / segments as f64;
+    let k = (4.0 / 3.0) * ops::tan(step / 4.0);
+
+    let mut result = Vec::with_capacity(segments);
+    let mut angle = start_angle;
+    for _ in 0..segments {
+        let next = angle + step;
+        let (s0, c0) = ops::sin_cos(angle);
+        let (s1, c1) = ops::sin_cos(next);
+        let p0 = (center.0 + rx * c0, center.1 + ry * s0);
+        let p1 = (center.0 + rx * c1, center.1 + ry * s1);
+        let control1 = (p0.0 - k * rx * s0, p0.1 + k * ry * c0);
+        let control2 = (p1.0 + k * rx * s1, p1.1 - k * ry * c1);
+        result.push(PathOp::CubicTo(control1, control2, p1));
+        angle = next;
+    }
+    result
+}
+
+/// A single flattened subpath: a polyline approximation of one `MoveTo`..
+/// (`Close`)? run of ops, within some tolerance.
+#[derive(Debug, Clone)]
+pub struct Contour {
+    pub points: Vec<(f64, f64)>,
+    pub closed: bool,
+}
+
+/// Flattens a sequence of [`PathOp`]s into polylines, one per subpath,
+/// via adaptive subdivision: a cubic is split at its midpoint until the
+/// curve lies within `tolerance` of the chord, and arcs are
+/// flattened the same way after converting them to cubics.
+pub(crate) fn flatten(ops: &[PathOp], tolerance: f64) -> Vec<Contour> {
+    let mut contours = Vec::new();
+    let mut points: Vec<(f64, f64)> = Vec::new();
+    let mut cur = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+
+    macro_rules! finish_contour {
+        ($closed:expr) => {
+            if points.len() > 1 {
+                contours.push(Contour {
+                    points: core::mem::take(&mut points),
+                    closed: $closed,
+                });
+            } else {
+                points.clear();
+            }
+        };
+    }
+
+    for op in ops {
+        match *op {
+            PathOp::MoveTo(x, y) => {
+                finish_contour!(false);
+                cur = (x, y);
+                start = cur;
+                points.push(cur);
+            }
+            PathOp::LineTo(x, y) => {
+                cur = (x, y);
+                points.push(cur);
+            }
+            PathOp::CubicTo(c1, c2, end) => {
+                flatten_cubic(cur, c1, c2, end, tolerance, 0, &mut points);
+                cur = end;
+            }
+            PathOp::Arc(rx, ry, sweep, end) => {
+                for cubic in arc_to_cubics(cur, rx, ry, sweep, end) {
+                    match cubic {
+                        PathOp::CubicTo(c1, c2, e) => {
+                            flatten_cubic(cur, c1, c2, e, tolerance, 0, &mut points);
+                            cur = e;
+                        }
+                        PathOp::LineTo(x, y) => {
+                            cur = (x, y);
+                            points.push(cur);
+                        }
+                        _ => unreachable!("arc_to_cubics only emits CubicTo/LineTo"),
+                    }
+                }
+            }
+            PathOp::Close => {
+                cur = start;
+                finish_contour!(true);
+            }
+        }
+    }
+    finish_contour!(false);
+
+    contours
+}
+
+fn flatten_cubic(
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    p3: (f64, f64),
+    tolerance: f64,
+    depth: u32,
+    out: &mut Vec<(f64, f64)>,
+) {
+    if depth >= 24 || cubic_is_flat(p0, p1, p2, p3, tolerance) {
+        out.push(p3);
+        return;
+    }
+
+    let p01 = midpoint(p0, p1);
+    let p12 = midpoint(p1, p2);
+    let p23 = midpoint(p2, p3);
+    let p012 = midpoint(p01, p12);
+    let p123 = midpoint(p12, p23);
+    let p0123 = midpoint(p012, p123);
+
+    flatten_cubic(p0, p01, p012, p0123, tolerance, depth + 1, out);
+    flatten_cubic(p0123, p123, p23, p3, tolerance, depth + 1, out);
+}
+
+fn midpoint(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+/// A standard cubic flatness test: the per-axis maxima of the squared
+/// offsets `3*p1 - 2*p0 - p3` and `3*p2 - 2*p3 - p0` (each control point's
+/// displacement from its third of the chord, times three), summed and
+/// compared against `16 * tolerance^2`, which keeps the curve within
+/// `tolerance` of the chord.
+fn cubic_is_flat(p0: (f64, f64), p1: (f64, f64), p2: (f64, f64), p3: (f64, f64), tolerance: f64) -> bool {
+    let ux = ops::powi(3.0 * p1.0 - 2.0 * p0.0 - p3.0, 2);
+    let uy = ops::powi(3.0 * p1.1 - 2.0 * p0.1 - p3.1, 2);
+    let vx = ops::powi(3.0 * p2.0 - 2.0 * p3.0 - p0.0, 2);
+    let vy = ops::powi(3.0 * p2.1 - 2.0 * p3.1 - p0.1, 2);
+    ux.max(vx) + uy.max(vy) <= 16.0 * tolerance * tolerance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_svg_string_renders_each_op() {
+        let ops = [
+            PathOp::MoveTo(0.0, 0.0),
+            PathOp::LineTo(10.0, 0.0),
+            PathOp::Arc(5.0, 5.0, true, (10.0, 10.0)),
+            PathOp::Close,
+        ];
+        assert_eq!(
+            to_svg_string(&ops),
+            "M 0.0000 0.0000 L 10.0000 0.0000 A 5.0000 5.0000 0 0 1 10.0000 10.0000 Z"
+        );
+    }
+
+    #[test]
+    fn arc_to_cubics_quarter_circle_hits_the_endpoint() {
+        // A sweep from (10, 0) to (0, 10) around the origin is a 90 degree
+        // arc, so however many segments it's split into, the last one
+        // should end exactly at the requested endpoint.
+        let result = arc_to_cubics((10.0, 0.0), 10.0, 10.0, true, (0.0, 10.0));
+        match result.last().unwrap() {
+            PathOp::CubicTo(_, _, end) => {
+                assert!((end.0 - 0.0).abs() < 1e-9);
+                assert!((end.1 - 10.0).abs() < 1e-9);
+            }
+            _ => panic!("expected a CubicTo"),
+        }
+    }
+
+    #[test]
+    fn degenerate_radius_arc_falls_back_to_a_line() {
+        let result = arc_to_cubics((0.0, 0.0), 0.0, 0.0, true, (5.0, 5.0));
+        assert_eq!(result, [PathOp::LineTo(5.0, 5.0)]);
+    }
+}
@@ -0,0 +1,237 @@
+//! Analytic anti-aliased rasterization of a squircle into an 8-bit alpha
+//! coverage mask, for use as a texture or clip mask without an SVG renderer
+//! in the loop.
+//!
+//! The outline is flattened into a polygon (reusing
+//! [`crate::path_ops::flatten`]) and scan-converted by accumulating signed,
+//! per-pixel partial-coverage deltas as each polygon edge crosses a
+//! scanline, then taking a running sum across each row to turn those deltas
+//! into coverage in `[0, 1]`. For the common uniform-radius, zero-smoothing
+//! case (an ordinary rounded rectangle) an exact signed-distance formula is
+//! used instead, since it needs no flattening and produces crisper corners.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{get_path_ops, ops, path_ops, SquircleParams};
+
+/// Rasterizes `params` into a row-major 8-bit alpha coverage mask of size
+/// `px_width` x `px_height`. The squircle's `(0, 0)..(width, height)`
+/// coordinate space is scaled to fill the pixel buffer.
+pub fn rasterize(params: &SquircleParams, px_width: usize, px_height: usize) -> Vec<u8> {
+    if px_width == 0 || px_height == 0 {
+        return Vec::new();
+    }
+
+    if let Some(radius) = uniform_zero_smoothing_radius(params) {
+        return rasterize_rounded_rect(params.width, params.height, radius, px_width, px_height);
+    }
+
+    rasterize_generic(params, px_width, px_height)
+}
+
+/// `Some(radius)` if every corner shares the same radius and
+/// `corner_smoothing` is zero, i.e. the squircle is an ordinary rounded
+/// rectangle with no superellipse blending.
+fn uniform_zero_smoothing_radius(params: &SquircleParams) -> Option<f64> {
+    if params.corner_smoothing != 0.0 {
+        return None;
+    }
+
+    let radius = params.corner_radius.unwrap_or(0.0);
+    let matches = |corner: Option<f64>| corner.unwrap_or(radius) == radius;
+
+    if matches(params.top_left_corner_radius)
+        && matches(params.top_right_corner_radius)
+        && matches(params.bottom_left_corner_radius)
+        && matches(params.bottom_right_corner_radius)
+    {
+        Some(radius.min(params.width / 2.0).min(params.height / 2.0))
+    } else {
+        None
+    }
+}
+
+/// Rasterizes an axis-aligned rounded rectangle via the signed-distance
+/// formula for a round box: `d = length(max(q, 0)) + min(max(q.x, q.y), 0) -
+/// radius`, with per-pixel coverage `clamp(0.5 - d, 0, 1)`.
+fn rasterize_rounded_rect(width: f64, height: f64, radius: f64, px_width: usize, px_height: usize) -> Vec<u8> {
+    let sx = px_width as f64 / width;
+    let sy = px_height as f64 / height;
+    let half_w = px_width as f64 / 2.0;
+    let half_h = px_height as f64 / 2.0;
+    let radius_px = radius * sx.min(sy);
+
+    let mut mask = vec![0u8; px_width * px_height];
+    for y in 0..px_height {
+        for x in 0..px_width {
+            let px = (x as f64 + 0.5) - half_w;
+            let py = (y as f64 + 0.5) - half_h;
+
+            let qx = px.abs() - (half_w - radius_px);
+            let qy = py.abs() - (half_h - radius_px);
+            let outside = ops::sqrt(ops::powi(qx.max(0.0), 2) + ops::powi(qy.max(0.0), 2));
+            let inside = qx.max(qy).min(0.0);
+            let distance = outside + inside - radius_px;
+
+            mask[y * px_width + x] = ((0.5 - distance).clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+        }
+    }
+    mask
+}
+
+/// Rasterizes the general case by flattening the outline to polylines and
+/// scan-converting them with analytic edge anti-aliasing.
+fn rasterize_generic(params: &SquircleParams, px_width: usize, px_height: usize) -> Vec<u8> {
+    let sx = px_width as f64 / params.width;
+    let sy = px_height as f64 / params.height;
+    let tolerance = (0.5 / sx.max(sy)).max(1e-6);
+    let contours = path_ops::flatten(&get_path_ops(params), tolerance);
+
+    let row_stride = px_width + 1;
+    let mut acc = vec![0.0f64; row_stride * px_height];
+
+    for contour in &contours {
+        let points = &contour.points;
+        let n = points.len();
+        if n < 2 {
+            continue;
+        }
+        let edges = if contour.closed { n } else { n - 1 };
+        for i in 0..edges {
+            let (ax, ay) = points[i];
+            let (bx, by) = points[(i + 1) % n];
+            accumulate_edge(&mut acc, px_width, px_height, (ax * sx, ay * sy), (bx * sx, by * sy));
+        }
+    }
+
+    let mut mask = vec![0u8; px_width * px_height];
+    for y in 0..px_height {
+        let row = &acc[y * row_stride..y * row_stride + row_stride];
+        let mut coverage = 0.0;
+        for x in 0..px_width {
+            coverage += row[x];
+            mask[y * px_width + x] = (coverage.abs().min(1.0) * 255.0 + 0.5) as u8;
+        }
+    }
+    mask
+}
+
+/// Deposits one polygon edge's contribution into the per-row accumulation
+/// buffer (`width + 1` cells per row), using the nonzero winding
+/// convention. For each scanline the edge crosses, the exact area to the
+/// right of the edge within every pixel cell it touches is computed via
+/// [`integrate_clamped_ramp`], and the cell-to-cell differences of that area
+/// are written as deltas so a running sum across the row reconstructs the
+/// coverage.
+fn accumulate_edge(acc: &mut [f64], width: usize, height: usize, p0: (f64, f64), p1: (f64, f64)) {
+    if (p0.1 - p1.1).abs() < f64::EPSILON {
+        return;
+    }
+    let (dir, p0, p1) = if p0.1 < p1.1 { (1.0, p0, p1) } else { (-1.0, p1, p0) };
+
+    let y_top = p0.1.max(0.0);
+    let y_bottom = p1.1.min(height as f64);
+    if y_top >= y_bottom {
+        return;
+    }
+
+    let dxdy = (p1.0 - p0.0) / (p1.1 - p0.1);
+    let row_stride = width + 1;
+    let row0 = ops::floor(y_top) as usize;
+    let row1 = (ops::ceil(y_bottom) as usize).min(height);
+
+    for row in row0..row1 {
+        let y_lo = (row as f64).max(p0.1);
+        let y_hi = ((row + 1) as f64).min(p1.1);
+        let dy = y_hi - y_lo;
+        if dy <= 0.0 {
+            continue;
+        }
+
+        let x_start = p0.0 + dxdy * (y_lo - p0.1);
+        let x_end = p0.0 + dxdy * (y_hi - p0.1);
+
+        let xmin = x_start.min(x_end).max(0.0);
+        let xmax = x_start.max(x_end).min(width as f64);
+        if xmax <= xmin {
+            if x_start.max(x_end) <= 0.0 {
+                acc[row * row_stride] += dir * dy;
+            }
+            continue;
+        }
+
+        let c0 = ops::floor(xmin) as i64;
+        let c1 = (ops::ceil(xmax) as i64).min(width as i64);
+
+        let mut prev = 0.0;
+        for c in c0..=c1 {
+            let u0 = (c as f64 + 1.0) - x_start;
+            let u1 = (c as f64 + 1.0) - x_end;
+            let direct = integrate_clamped_ramp(dy, u0, u1);
+            let delta = direct - prev;
+            prev = direct;
+            if c >= 0 {
+                acc[row * row_stride + c as usize] += dir * delta;
+            }
+        }
+    }
+}
+
+/// The integral over `[0, dy]` of `clamp(lerp(u0, u1, t / dy), 0, 1)`, i.e.
+/// the area under a linear ramp from `u0` to `u1` after clamping it to
+/// `[0, 1]`. Exact for any ramp, since a clamped linear function is itself
+/// piecewise-linear.
+fn integrate_clamped_ramp(dy: f64, u0: f64, u1: f64) -> f64 {
+    if dy <= 0.0 {
+        return 0.0;
+    }
+    let (lo, hi) = if u0 <= u1 { (u0, u1) } else { (u1, u0) };
+    if hi - lo < 1e-12 {
+        return dy * lo.clamp(0.0, 1.0);
+    }
+
+    let value_at = |t: f64| lo + (hi - lo) * t / dy;
+    let t0 = (((0.0 - lo) / (hi - lo)) * dy).clamp(0.0, dy);
+    let t1 = (((1.0 - lo) / (hi - lo)) * dy).clamp(0.0, dy);
+    let v0 = value_at(t0);
+    let v1 = value_at(t1);
+
+    let mid_area = (t1 - t0) * (v0 + v1) / 2.0;
+    let above_len = (dy - t1).max(0.0);
+    mid_area + above_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rounded_rect_params(size: f64, radius: f64) -> SquircleParams {
+        SquircleParams {
+            width: size,
+            height: size,
+            corner_smoothing: 0.0,
+            corner_radius: Some(radius),
+            top_left_corner_radius: None,
+            top_right_corner_radius: None,
+            bottom_right_corner_radius: None,
+            bottom_left_corner_radius: None,
+            preserve_smoothing: None,
+            normalization_strategy: None,
+        }
+    }
+
+    #[test]
+    fn center_is_opaque_and_corner_is_clear() {
+        let params = rounded_rect_params(40.0, 10.0);
+        let mask = rasterize(&params, 40, 40);
+        assert_eq!(mask[19 * 40 + 19], 255);
+        assert_eq!(mask[0], 0);
+    }
+
+    #[test]
+    fn empty_pixel_dimensions_produce_an_empty_mask() {
+        let params = rounded_rect_params(40.0, 10.0);
+        assert!(rasterize(&params, 0, 40).is_empty());
+        assert!(rasterize(&params, 40, 0).is_empty());
+    }
+}
@@ -0,0 +1,185 @@
+//! Geometry queries over a generated squircle: area, perimeter, bounding
+//! box, and point containment, for hit-testing and layout math rather than
+//! just rendering.
+
+use alloc::vec::Vec;
+
+use crate::{get_path_ops, ops, path_ops, SquircleParams};
+
+/// An axis-aligned bounding box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+/// A squircle with query methods layered on top of [`SquircleParams`].
+///
+/// Every query that needs to reason about the curved outline (`area`,
+/// `perimeter`, `contains_point`) flattens it into a polyline at the given
+/// `tolerance` first, trading accuracy for speed.
+pub struct Squircle {
+    params: SquircleParams,
+}
+
+impl Squircle {
+    pub fn new(params: SquircleParams) -> Self {
+        Self { params }
+    }
+
+    /// The squircle is always inscribed exactly within its `width` x
+    /// `height` rectangle, so this needs no flattening.
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox {
+            min_x: 0.0,
+            min_y: 0.0,
+            max_x: self.params.width,
+            max_y: self.params.height,
+        }
+    }
+
+    /// The enclosed area, via the shoelace formula over the flattened
+    /// outline.
+    pub fn area(&self, tolerance: f64) -> f64 {
+        self.flatten(tolerance)
+            .iter()
+            .map(|contour| polygon_area(&contour.points).abs())
+            .sum()
+    }
+
+    /// The outline length, via summed segment lengths over the flattened
+    /// outline.
+    pub fn perimeter(&self, tolerance: f64) -> f64 {
+        self.flatten(tolerance)
+            .iter()
+            .map(|contour| polygon_perimeter(&contour.points, contour.closed))
+            .sum()
+    }
+
+    /// Whether `(x, y)` lies inside the squircle, via an even-odd
+    /// ray-crossing test against the flattened outline.
+    pub fn contains_point(&self, x: f64, y: f64, tolerance: f64) -> bool {
+        self.flatten(tolerance)
+            .iter()
+            .filter(|contour| point_in_polygon(&contour.points, x, y))
+            .count()
+            % 2
+            == 1
+    }
+
+    fn flatten(&self, tolerance: f64) -> Vec<path_ops::Contour> {
+        path_ops::flatten(&get_path_ops(&self.params), tolerance)
+    }
+}
+
+fn polygon_area(poly: &[(f64, f64)]) -> f64 {
+    let n = poly.len();
+    let mut sum = 0.0;
+    for i in 0..n {
+        let (x0, y0) = poly[i];
+        let (x1, y1) = poly[(i + 1) % n];
+        sum += x0 * y1 - x1 * y0;
+    }
+    sum / 2.0
+}
+
+fn polygon_perimeter(poly: &[(f64, f64)], closed: bool) -> f64 {
+    let n = poly.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let edges = if closed { n } else { n - 1 };
+    let mut total = 0.0;
+    for i in 0..edges {
+        let a = poly[i];
+        let b = poly[(i + 1) % n];
+        total += ops::sqrt(ops::powi(b.0 - a.0, 2) + ops::powi(b.1 - a.1, 2));
+    }
+    total
+}
+
+fn point_in_polygon(poly: &[(f64, f64)], px: f64, py: f64) -> bool {
+    let n = poly.len();
+    let mut inside = false;
+    let mut j = n - 1;
+    for i in 0..n {
+        let (xi, yi) = poly[i];
+        let (xj, yj) = poly[j];
+        if (yi > py) != (yj > py) && px < (xj - xi) * (py - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rectangle(width: f64, height: f64) -> Squircle {
+        Squircle::new(SquircleParams {
+            width,
+            height,
+            corner_smoothing: 0.0,
+            corner_radius: None,
+            top_left_corner_radius: None,
+            top_right_corner_radius: None,
+            bottom_right_corner_radius: None,
+            bottom_left_corner_radius: None,
+            preserve_smoothing: None,
+            normalization_strategy: None,
+        })
+    }
+
+    #[test]
+    fn zero_radius_area_and_perimeter_match_a_plain_rectangle() {
+        let squircle = rectangle(10.0, 4.0);
+        assert!((squircle.area(0.01) - 40.0).abs() < 1e-6);
+        assert!((squircle.perimeter(0.01) - 28.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn bounding_box_is_the_input_rectangle() {
+        let squircle = rectangle(10.0, 4.0);
+        assert_eq!(
+            squircle.bounding_box(),
+            BoundingBox { min_x: 0.0, min_y: 0.0, max_x: 10.0, max_y: 4.0 }
+        );
+    }
+
+    #[test]
+    fn contains_point_matches_the_rectangle_interior() {
+        let squircle = rectangle(10.0, 4.0);
+        assert!(squircle.contains_point(5.0, 2.0, 0.01));
+        assert!(!squircle.contains_point(-1.0, -1.0, 0.01));
+    }
+
+    #[test]
+    fn a_smoothed_curved_corner_stays_inside_the_bounding_box() {
+        // A regression test for the arc-to-center `sign` bug: picking the
+        // wrong side of the chord bows every corner with `corner_smoothing
+        // > 0` outward, well past the squircle's own bounding box.
+        let squircle = Squircle::new(SquircleParams {
+            width: 100.0,
+            height: 100.0,
+            corner_smoothing: 0.6,
+            corner_radius: Some(20.0),
+            top_left_corner_radius: None,
+            top_right_corner_radius: None,
+            bottom_right_corner_radius: None,
+            bottom_left_corner_radius: None,
+            preserve_smoothing: Some(false),
+            normalization_strategy: None,
+        });
+
+        for contour in squircle.flatten(0.01) {
+            for (x, y) in contour.points {
+                assert!((-1e-6..=100.0 + 1e-6).contains(&x), "x {x} escaped the bounding box");
+                assert!((-1e-6..=100.0 + 1e-6).contains(&y), "y {y} escaped the bounding box");
+            }
+        }
+    }
+}
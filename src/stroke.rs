@@ -0,0 +1,371 @@
+//! Stroke (outline) generation for paths built from [`crate::path_ops`].
+//!
+//! A stroke is produced by flattening the source path into polylines (see
+//! [`crate::path_ops::flatten`]), offsetting each polyline inward and
+//! outward by half the stroke width along its vertex normals, and
+//! reconnecting the offset vertices with the requested [`LineJoin`] (caps
+//! apply only where a subpath isn't closed).
+
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    ops,
+    path_ops::{self, PathOp},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeStyle {
+    pub width: f64,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    pub miter_limit: f64,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            miter_limit: 4.0,
+        }
+    }
+}
+
+/// Strokes every subpath of `ops`, returning a new path (as [`PathOp`]s)
+/// representing the outline. Closed subpaths produce two nested rings (an
+/// outer and an inner, wound oppositely) suitable for even-odd or nonzero
+/// filling; open subpaths produce a single ring closed off with `style.cap`
+/// at both ends.
+pub fn stroke_ops(ops: &[PathOp], style: &StrokeStyle, tolerance: f64) -> Vec<PathOp> {
+    let half = style.width / 2.0;
+    let mut result = Vec::new();
+
+    for contour in path_ops::flatten(ops, tolerance) {
+        if contour.points.len() < 2 {
+            continue;
+        }
+
+        if contour.closed {
+            let outer = offset_polygon(
+                &contour.points,
+                JoinParams { distance: half, join: style.join, miter_limit: style.miter_limit },
+            );
+            let mut inner = offset_polygon(
+                &contour.points,
+                JoinParams { distance: -half, join: style.join, miter_limit: style.miter_limit },
+            );
+            inner.reverse();
+            result.extend(polygon_to_ops(&outer));
+            result.extend(polygon_to_ops(&inner));
+        } else {
+            result.extend(polygon_to_ops(&stroke_open_contour(
+                &contour.points,
+                half,
+                style,
+            )));
+        }
+    }
+
+    result
+}
+
+/// Renders an SVG `d` attribute for the stroked outline of `ops`.
+pub fn stroke_svg_string(ops: &[PathOp], style: &StrokeStyle, tolerance: f64) -> String {
+    path_ops::to_svg_string(&stroke_ops(ops, style, tolerance))
+}
+
+fn stroke_open_contour(points: &[(f64, f64)], half: f64, style: &StrokeStyle) -> Vec<(f64, f64)> {
+    let left = offset_polyline(
+        points,
+        JoinParams { distance: half, join: style.join, miter_limit: style.miter_limit },
+    );
+    let mut right = offset_polyline(
+        points,
+        JoinParams { distance: -half, join: style.join, miter_limit: style.miter_limit },
+    );
+    right.reverse();
+
+    let end_tangent = unit(sub(points[points.len() - 1], points[points.len() - 2]));
+    let start_tangent = unit(sub(points[0], points[1]));
+
+    let mut outline = Vec::with_capacity(left.len() + right.len() + 8);
+    outline.extend(left.iter().copied());
+    outline.extend(cap_points(
+        points[points.len() - 1],
+        half,
+        end_tangent,
+        style.cap,
+    ));
+    outline.extend(right.iter().copied());
+    outline.extend(cap_points(points[0], half, start_tangent, style.cap));
+    outline
+}
+
+fn cap_points(center: (f64, f64), radius: f64, outward: (f64, f64), cap: LineCap) -> Vec<(f64, f64)> {
+    match cap {
+        LineCap::Butt => Vec::new(),
+        LineCap::Square => {
+            let perp = (-outward.1, outward.0);
+            vec![
+                (
+                    center.0 - perp.0 * radius + outward.0 * radius,
+                    center.1 - perp.1 * radius + outward.1 * radius,
+                ),
+                (
+                    center.0 + perp.0 * radius + outward.0 * radius,
+                    center.1 + perp.1 * radius + outward.1 * radius,
+                ),
+            ]
+        }
+        LineCap::Round => {
+            let angle_outward = ops::atan2(outward.1, outward.0);
+            let start = angle_outward - FRAC_PI_2;
+            let end = angle_outward + FRAC_PI_2;
+            let segments = 12;
+            (0..=segments)
+                .map(|i| {
+                    let t = i as f64 / segments as f64;
+                    let angle = start + (end - start) * t;
+                    (center.0 + radius * ops::cos(angle), center.1 + radius * ops::sin(angle))
+                })
+                .collect()
+        }
+    }
+}
+
+/// The join behavior shared by every vertex of an offset polyline/polygon:
+/// how far to offset, and how to reconnect the offset edges at a vertex.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct JoinParams {
+    distance: f64,
+    join: LineJoin,
+    miter_limit: f64,
+}
+
+/// Offsets a closed polygon by `params.distance` along each vertex's
+/// outward normal (negative `distance` offsets inward), joining adjacent
+/// edges per `params`.
+fn offset_polygon(poly: &[(f64, f64)], params: JoinParams) -> Vec<(f64, f64)> {
+    let n = poly.len();
+    let sign = if signed_area(poly) >= 0.0 { 1.0 } else { -1.0 };
+    let mut result = Vec::with_capacity(n * 2);
+
+    for i in 0..n {
+        let prev = poly[(i + n - 1) % n];
+        let cur = poly[i];
+        let next = poly[(i + 1) % n];
+        push_joined_offset(&mut result, prev, cur, next, sign, params);
+    }
+
+    result
+}
+
+/// Offsets an open polyline by `params.distance`, joining interior vertices
+/// per `params` (the two endpoints are left as single offset points; the
+/// caller adds caps).
+fn offset_polyline(points: &[(f64, f64)], params: JoinParams) -> Vec<(f64, f64)> {
+    let n = points.len();
+    let mut result = Vec::with_capacity(n);
+
+    let first_normal = edge_normal(1.0, points[0], points[1]);
+    result.push(offset_point(points[0], first_normal, params.distance));
+
+    for i in 1..n - 1 {
+        push_joined_offset(&mut result, points[i - 1], points[i], points[i + 1], 1.0, params);
+    }
+
+    let last_normal = edge_normal(1.0, points[n - 2], points[n - 1]);
+    result.push(offset_point(points[n - 1], last_normal, params.distance));
+
+    result
+}
+
+fn push_joined_offset(
+    result: &mut Vec<(f64, f64)>,
+    prev: (f64, f64),
+    cur: (f64, f64),
+    next: (f64, f64),
+    sign: f64,
+    params: JoinParams,
+) {
+    let JoinParams { distance, join, miter_limit } = params;
+    let n1 = edge_normal(sign, prev, cur);
+    let n2 = edge_normal(sign, cur, next);
+    let p1 = offset_point(cur, n1, distance);
+    let p2 = offset_point(cur, n2, distance);
+
+    if close_enough(p1, p2) {
+        result.push(p1);
+        return;
+    }
+
+    match join {
+        LineJoin::Bevel => {
+            result.push(p1);
+            result.push(p2);
+        }
+        LineJoin::Round => result.extend(round_join(cur, n1, n2, distance)),
+        LineJoin::Miter => match miter_point(cur, n1, n2, distance, miter_limit) {
+            Some(m) => result.push(m),
+            None => {
+                result.push(p1);
+                result.push(p2);
+            }
+        },
+    }
+}
+
+fn round_join(cur: (f64, f64), n1: (f64, f64), n2: (f64, f64), distance: f64) -> Vec<(f64, f64)> {
+    let a1 = ops::atan2(n1.1, n1.0);
+    let a2 = ops::atan2(n2.1, n2.0);
+    let mut delta = a2 - a1;
+    while delta > PI {
+        delta -= 2.0 * PI;
+    }
+    while delta < -PI {
+        delta += 2.0 * PI;
+    }
+
+    let segments = (ops::ceil(delta.abs() / (PI / 8.0)) as usize).max(1);
+    (0..=segments)
+        .map(|i| {
+            let t = i as f64 / segments as f64;
+            let angle = a1 + delta * t;
+            (cur.0 + ops::cos(angle) * distance, cur.1 + ops::sin(angle) * distance)
+        })
+        .collect()
+}
+
+/// Computes the miter point for the join at `cur`, or `None` if the turn is
+/// too sharp for `miter_limit` (the caller should fall back to a bevel).
+fn miter_point(
+    cur: (f64, f64),
+    n1: (f64, f64),
+    n2: (f64, f64),
+    distance: f64,
+    miter_limit: f64,
+) -> Option<(f64, f64)> {
+    let bisector = (n1.0 + n2.0, n1.1 + n2.1);
+    let bisector_len = ops::sqrt(bisector.0 * bisector.0 + bisector.1 * bisector.1);
+    if bisector_len < 1e-6 {
+        return None;
+    }
+
+    // n1 and n2 are unit vectors, so |n1 + n2| = 2*cos(theta/2), where theta
+    // is the angle between them.
+    let cos_half = (bisector_len / 2.0).min(1.0);
+    if cos_half < 1e-6 {
+        return None;
+    }
+
+    let miter_scale = 1.0 / cos_half;
+    if miter_scale > miter_limit {
+        return None;
+    }
+
+    let (ux, uy) = (bisector.0 / bisector_len, bisector.1 / bisector_len);
+    Some((
+        cur.0 + ux * distance * miter_scale,
+        cur.1 + uy * distance * miter_scale,
+    ))
+}
+
+fn edge_normal(orientation_sign: f64, a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let (dx, dy) = sub(b, a);
+    let len = ops::sqrt(dx * dx + dy * dy).max(f64::EPSILON);
+    (orientation_sign * dy / len, orientation_sign * -dx / len)
+}
+
+fn offset_point(p: (f64, f64), normal: (f64, f64), distance: f64) -> (f64, f64) {
+    (p.0 + normal.0 * distance, p.1 + normal.1 * distance)
+}
+
+fn signed_area(poly: &[(f64, f64)]) -> f64 {
+    let n = poly.len();
+    let mut sum = 0.0;
+    for i in 0..n {
+        let (x0, y0) = poly[i];
+        let (x1, y1) = poly[(i + 1) % n];
+        sum += x0 * y1 - x1 * y0;
+    }
+    sum / 2.0
+}
+
+fn sub(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn unit(v: (f64, f64)) -> (f64, f64) {
+    let len = ops::sqrt(v.0 * v.0 + v.1 * v.1).max(f64::EPSILON);
+    (v.0 / len, v.1 / len)
+}
+
+fn close_enough(a: (f64, f64), b: (f64, f64)) -> bool {
+    (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9
+}
+
+fn polygon_to_ops(points: &[(f64, f64)]) -> Vec<PathOp> {
+    let mut ops = Vec::with_capacity(points.len() + 1);
+    if let Some(&(x, y)) = points.first() {
+        ops.push(PathOp::MoveTo(x, y));
+        for &(x, y) in &points[1..] {
+            ops.push(PathOp::LineTo(x, y));
+        }
+        ops.push(PathOp::Close);
+    }
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn miter_point_at_a_right_angle_is_sqrt_2_out() {
+        // Two perpendicular unit normals: the classic square-corner miter,
+        // whose tip sits `distance * sqrt(2)` from the vertex.
+        let m = miter_point((0.0, 0.0), (0.0, 1.0), (1.0, 0.0), 1.0, 4.0).unwrap();
+        let len = ops::sqrt(m.0 * m.0 + m.1 * m.1);
+        assert!((len - core::f64::consts::SQRT_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn miter_point_bails_out_past_the_limit() {
+        // A near-180-degree turn needs an enormous miter scale, so a modest
+        // limit should reject it and let the caller fall back to a bevel.
+        let m = miter_point((0.0, 0.0), (1.0, 0.0), (-0.99, 0.1), 1.0, 4.0);
+        assert!(m.is_none());
+    }
+
+    #[test]
+    fn offset_polygon_pushes_a_square_outward() {
+        // Offsetting a 2x2 square outward by 1 with bevel joins should
+        // leave every offset point 1 unit further out along some axis, i.e.
+        // twice the original half-extent from the center.
+        let square = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)];
+        let offset = offset_polygon(
+            &square,
+            JoinParams { distance: 1.0, join: LineJoin::Bevel, miter_limit: 4.0 },
+        );
+        for (x, y) in offset {
+            assert!(x <= -0.99 || x >= 2.99 || y <= -0.99 || y >= 2.99);
+        }
+    }
+}